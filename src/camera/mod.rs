@@ -5,17 +5,45 @@ use bevy::{
     window::PrimaryWindow,
 };
 
+use crate::planets::{Planet, Selected};
 use crate::MOUSE_SENSITIVITY;
 
+// How quickly the camera eases toward a followed planet (fraction per frame).
+const FOLLOW_LERP: f32 = 0.1;
+
+// Keyboard pan speed in world units per second at scale 1.0.
+const KEY_PAN_SPEED: f32 = 500.;
+// How fast the projection scale chases its target (rate per second: each frame
+// closes `ZOOM_LERP * dt` of the remaining gap, at most all of it).
+const ZOOM_LERP: f32 = 12.;
+// Bounds so the user can't zoom into a singularity or lose the system.
+const MIN_SCALE: f32 = 0.1;
+const MAX_SCALE: f32 = 50.;
+
 pub struct SpaceCameraPlugin;
 
 #[derive(Component)]
-struct MainCamera;
+pub(crate) struct MainCamera;
+
+/// The projection scale the camera smoothly interpolates toward.
+#[derive(Resource)]
+struct ZoomTarget(f32);
 
 impl Plugin for SpaceCameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, spawn_camera)
-            .add_systems(Update, (handle_camera_pan, zoom_control));
+        app.insert_resource(ZoomTarget(2.))
+            .add_systems(Startup, spawn_camera)
+            .add_systems(
+                Update,
+                (
+                    handle_camera_pan,
+                    handle_keyboard_pan,
+                    zoom_control,
+                    smooth_zoom,
+                    handle_clicking_planet,
+                    follow_selected,
+                ),
+            );
     }
 }
 
@@ -45,46 +73,136 @@ fn handle_camera_pan(
     }
 }
 
-// Handles clicking on a planet
+// Picks the planet under the cursor on left click and marks it `Selected`, or
+// clears the selection with the clear key to return to free panning.
 fn handle_clicking_planet(
+    mut commands: Commands,
     window: Query<&Window, With<PrimaryWindow>>,
     input: Res<Input<MouseButton>>,
-    mut camera_query: Query<&mut Transform, With<MainCamera>>,
+    keys: Res<Input<KeyCode>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    planet_query: Query<(Entity, &Transform), With<Planet>>,
+    selected_query: Query<Entity, With<Selected>>,
 ) {
-    let mut camera_transform = camera_query.single_mut();
+    // Clear the current selection and go back to free pan.
+    if keys.just_pressed(KeyCode::C) {
+        for entity in selected_query.iter() {
+            commands.entity(entity).remove::<Selected>();
+        }
+        return;
+    }
+
+    if !input.just_pressed(MouseButton::Left) {
+        return;
+    }
 
-    if let Some(position) = window.single().cursor_position() {
-        if input.just_pressed(MouseButton::Left) {
-            camera_transform.translation.x = position.x;
-            camera_transform.translation.y = position.y;
+    let Some(cursor) = window.single().cursor_position() else {
+        return;
+    };
+    let (camera, camera_transform) = camera_query.single();
+
+    // Convert the cursor pixel position into world space, accounting for the
+    // orthographic projection's current scale and pan.
+    let Some(world_cursor) = camera.viewport_to_world_2d(camera_transform, cursor) else {
+        return;
+    };
+
+    // Find the nearest planet whose rendered disc contains the cursor.
+    let mut nearest: Option<(Entity, f32)> = None;
+    for (entity, transform) in planet_query.iter() {
+        let center = transform.translation.truncate();
+        // The circle mesh has radius 0.5 before scaling, so the rendered
+        // radius is half the sprite's world-space diameter.
+        let radius = transform.scale.x * 0.5;
+        let distance = center.distance(world_cursor);
+        if distance <= radius && nearest.map_or(true, |(_, best)| distance < best) {
+            nearest = Some((entity, distance));
+        }
+    }
+
+    if let Some((entity, _)) = nearest {
+        for previous in selected_query.iter() {
+            commands.entity(previous).remove::<Selected>();
         }
+        commands.entity(entity).insert(Selected);
     }
 }
 
-// To zoom in and out
-fn zoom_control(
-    mut scroll: EventReader<MouseWheel>,
-    mut camera_query: Query<&mut OrthographicProjection, With<MainCamera>>,
+// Smoothly eases the camera toward the selected planet each frame.
+fn follow_selected(
+    selected_query: Query<&Transform, (With<Selected>, Without<MainCamera>)>,
+    mut camera_query: Query<&mut Transform, With<MainCamera>>,
 ) {
-    let mut projection = camera_query.single_mut();
+    let Ok(target) = selected_query.get_single() else {
+        return;
+    };
+    let mut camera_transform = camera_query.single_mut();
 
+    let current = camera_transform.translation.truncate();
+    let goal = target.translation.truncate();
+    let next = current.lerp(goal, FOLLOW_LERP);
+    camera_transform.translation.x = next.x;
+    camera_transform.translation.y = next.y;
+}
+
+// Pans the camera with WASD/arrow keys. Pan speed is scaled by the projection
+// scale so it feels constant at every zoom level and by delta time so it is
+// frame-rate independent.
+fn handle_keyboard_pan(
+    keys: Res<Input<KeyCode>>,
+    time: Res<Time>,
+    mut camera_query: Query<(&mut Transform, &OrthographicProjection), With<MainCamera>>,
+) {
+    let (mut transform, projection) = camera_query.single_mut();
+
+    let mut direction = Vec2::ZERO;
+    if keys.any_pressed([KeyCode::W, KeyCode::Up]) {
+        direction.y += 1.;
+    }
+    if keys.any_pressed([KeyCode::S, KeyCode::Down]) {
+        direction.y -= 1.;
+    }
+    if keys.any_pressed([KeyCode::A, KeyCode::Left]) {
+        direction.x -= 1.;
+    }
+    if keys.any_pressed([KeyCode::D, KeyCode::Right]) {
+        direction.x += 1.;
+    }
+
+    if direction != Vec2::ZERO {
+        let delta =
+            direction.normalize() * KEY_PAN_SPEED * projection.scale * time.delta_seconds();
+        transform.translation.x += delta.x;
+        transform.translation.y += delta.y;
+    }
+}
+
+// Mouse wheel nudges the zoom target; the actual scale eases toward it in
+// `smooth_zoom` rather than snapping.
+fn zoom_control(mut scroll: EventReader<MouseWheel>, mut zoom: ResMut<ZoomTarget>) {
     // 1 for zoom in, -1 for zoom out
     for ev in scroll.read() {
-        match ev.unit {
-            MouseScrollUnit::Line => {
-                if ev.y == -1. {
-                    projection.scale *= MOUSE_SENSITIVITY;
-                } else if ev.y == 1. {
-                    projection.scale /= MOUSE_SENSITIVITY;
-                }
-            }
-            MouseScrollUnit::Pixel => {
-                if ev.y == -1. {
-                    projection.scale *= 1.25;
-                } else if ev.y == 1. {
-                    projection.scale /= 1.25;
-                }
-            }
+        let step = match ev.unit {
+            MouseScrollUnit::Line => MOUSE_SENSITIVITY,
+            MouseScrollUnit::Pixel => 1.25,
+        };
+        if ev.y < 0. {
+            zoom.0 *= step;
+        } else if ev.y > 0. {
+            zoom.0 /= step;
         }
     }
+    zoom.0 = zoom.0.clamp(MIN_SCALE, MAX_SCALE);
+}
+
+// Frame-rate-independent interpolation of the projection scale toward the
+// target set by `zoom_control`.
+fn smooth_zoom(
+    time: Res<Time>,
+    zoom: Res<ZoomTarget>,
+    mut camera_query: Query<&mut OrthographicProjection, With<MainCamera>>,
+) {
+    let mut projection = camera_query.single_mut();
+    let t = (ZOOM_LERP * time.delta_seconds()).min(1.);
+    projection.scale += (zoom.0 - projection.scale) * t;
 }
@@ -1,5 +1,7 @@
 use bevy::{diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin}, prelude::*};
 
+use crate::planets::{Mass, Planet, Position, Selected, SimTime, Velocity, AU};
+
 // Diagnostics
 
 /// Fps marker
@@ -10,12 +12,20 @@ struct FpsRoot;
 #[derive(Component)]
 struct FpsText;
 
+/// Selected-body info panel marker
+#[derive(Component)]
+struct InfoRoot;
+
+/// Selected-body info text marker
+#[derive(Component)]
+struct InfoText;
+
 pub struct SpaceDiagnosticsPlugin;
 
 impl Plugin for SpaceDiagnosticsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, setup_fps)
-            .add_systems(Update, update_fps);
+        app.add_systems(Startup, (setup_fps, setup_info))
+            .add_systems(Update, (update_fps, update_info, update_sim_speed));
     }
 }
 
@@ -65,6 +75,15 @@ fn setup_fps(mut commands: Commands) {
                         ..default()
                     },
                 },
+                // Simulation speed multiplier, updated in `update_sim_speed`.
+                TextSection {
+                    value: "".into(),
+                    style: TextStyle {
+                        font_size: 16.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                },
             ]),
             ..Default::default()
         },
@@ -76,6 +95,98 @@ fn setup_fps(mut commands: Commands) {
     commands.entity(root).push_children(&[text_fps]);
 }
 
+// Setup the selected-body info panel, reusing the FPS panel's styling. It
+// starts hidden and is revealed only while a body is selected.
+fn setup_info(mut commands: Commands) {
+    let info_bundle = (
+        InfoRoot,
+        NodeBundle {
+            background_color: BackgroundColor(Color::BLACK.with_a(0.5)),
+            z_index: ZIndex::Global(i32::MAX),
+            visibility: Visibility::Hidden,
+            style: Style {
+                position_type: PositionType::Absolute,
+                right: Val::Percent(1.),
+                top: Val::Percent(1.),
+                bottom: Val::Auto,
+                left: Val::Auto,
+                padding: UiRect::all(Val::Px(4.)),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    );
+    let text_info_bundle = (
+        InfoText,
+        TextBundle {
+            text: Text::from_section(
+                "",
+                TextStyle {
+                    font_size: 16.0,
+                    color: Color::WHITE,
+                    ..default()
+                },
+            ),
+            ..Default::default()
+        },
+    );
+
+    let root = commands.spawn(info_bundle).id();
+    let text_info = commands.spawn(text_info_bundle).id();
+
+    commands.entity(root).push_children(&[text_info]);
+}
+
+// Update the info panel each frame, collapsing it when nothing is selected.
+fn update_info(
+    selected_query: Query<(&Name, &Mass, &Velocity, &Position), (With<Planet>, With<Selected>)>,
+    planet_query: Query<(&Mass, &Position), With<Planet>>,
+    mut root_query: Query<&mut Visibility, With<InfoRoot>>,
+    mut text_query: Query<&mut Text, With<InfoText>>,
+) {
+    let mut root_visibility = root_query.single_mut();
+
+    let Ok((name, mass, velocity, position)) = selected_query.get_single() else {
+        *root_visibility = Visibility::Hidden;
+        return;
+    };
+    *root_visibility = Visibility::Inherited;
+
+    // Distance from the Sun (the most massive body), in AU.
+    let distance_au = planet_query
+        .iter()
+        .max_by(|a, b| a.0 .0.partial_cmp(&b.0 .0).unwrap())
+        .map(|(_, sun_position)| {
+            let dx = position.x - sun_position.x;
+            let dy = position.y - sun_position.y;
+            (dx * dx + dy * dy).sqrt() / AU
+        })
+        .unwrap_or(0.);
+
+    // Speed in km/s.
+    let speed = (velocity.x * velocity.x + velocity.y * velocity.y).sqrt() / 1000.;
+
+    let mut text = text_query.single_mut();
+    text.sections[0].value = format!(
+        "{}\nMass: {:.3e} kg\nSpeed: {:.2} km/s\nDistance: {:.3} AU",
+        name.as_str(),
+        mass.0,
+        speed,
+        distance_au,
+    );
+}
+
+// Show the current simulation speed multiplier next to the FPS counter.
+fn update_sim_speed(sim: Res<SimTime>, mut fps_query: Query<&mut Text, With<FpsText>>) {
+    for mut text in &mut fps_query {
+        text.sections[2].value = if sim.is_paused() {
+            " | paused".into()
+        } else {
+            format!(" | {:.2}x", sim.multiplier())
+        };
+    }
+}
+
 // Update the fps
 fn update_fps(diagnostics: Res<DiagnosticsStore>, mut fps_query: Query<&mut Text, With<FpsText>>) {
     for mut fps in &mut fps_query {
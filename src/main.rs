@@ -13,6 +13,9 @@ use diagnostics::SpaceDiagnosticsPlugin;
 mod planets;
 use planets::PlanetsPlugin;
 
+mod starfield;
+use starfield::StarfieldPlugin;
+
 // A program to simulate F = G (m1m2/r**2)
 
 // Mouse sensitivity
@@ -26,6 +29,7 @@ fn main() {
             SpaceCameraPlugin,
             SpaceDiagnosticsPlugin,
             PlanetsPlugin,
+            StarfieldPlugin,
         ))
         .insert_resource(ClearColor(Color::BLACK))
         .add_systems(Startup, setup_window)
@@ -0,0 +1,216 @@
+//! Barnes–Hut quadtree approximation of the gravitational acceleration.
+//!
+//! Building a tree over all body positions and treating sufficiently distant
+//! clusters as a single center-of-mass point turns the N-body step from the
+//! exact O(n^2) sum into roughly O(n log n), which is what lets the simulation
+//! scale from nine planets to an asteroid field.
+
+use crate::planets::GRAVITY;
+
+// Maximum subdivision depth. Bodies that remain in the same quadrant this many
+// levels down (coincident, or closer than the quad resolution) are summed into
+// one leaf instead of recursing forever and overflowing the stack.
+const MAX_DEPTH: usize = 64;
+
+// A square region, stored as its center and half-width.
+#[derive(Clone, Copy)]
+struct Quad {
+    cx: f64,
+    cy: f64,
+    half: f64,
+}
+
+impl Quad {
+    // The child quadrant containing (x, y): 0=NE, 1=NW, 2=SW, 3=SE.
+    fn quadrant(&self, x: f64, y: f64) -> usize {
+        match (x >= self.cx, y >= self.cy) {
+            (true, true) => 0,
+            (false, true) => 1,
+            (false, false) => 2,
+            (true, false) => 3,
+        }
+    }
+
+    // The square covering the given child quadrant.
+    fn child(&self, quadrant: usize) -> Quad {
+        let quarter = self.half / 2.;
+        let (dx, dy) = match quadrant {
+            0 => (quarter, quarter),
+            1 => (-quarter, quarter),
+            2 => (-quarter, -quarter),
+            _ => (quarter, -quarter),
+        };
+        Quad {
+            cx: self.cx + dx,
+            cy: self.cy + dy,
+            half: quarter,
+        }
+    }
+}
+
+// A node accumulates the total mass and center-of-mass of its contents. A node
+// is a leaf while it holds a single body; inserting a second body subdivides it.
+struct Node {
+    quad: Quad,
+    mass: f64,
+    com_x: f64,
+    com_y: f64,
+    body: Option<usize>,
+    children: Option<Box<[Option<Node>; 4]>>,
+}
+
+impl Node {
+    fn new(quad: Quad) -> Self {
+        Node {
+            quad,
+            mass: 0.,
+            com_x: 0.,
+            com_y: 0.,
+            body: None,
+            children: None,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.mass == 0. && self.body.is_none() && self.children.is_none()
+    }
+
+    // Insert body `index` from `bodies` (x, y, mass) into this subtree.
+    fn insert(&mut self, index: usize, bodies: &[(f64, f64, f64)], depth: usize) {
+        let (x, y, m) = bodies[index];
+
+        if self.is_empty() {
+            self.body = Some(index);
+            self.mass = m;
+            self.com_x = x;
+            self.com_y = y;
+            return;
+        }
+
+        // At the depth limit, stop subdividing and merge colocated bodies into
+        // this single leaf by folding the new mass into the center-of-mass.
+        if depth >= MAX_DEPTH {
+            self.accumulate(x, y, m);
+            return;
+        }
+
+        // An internal node already has children; push the new body down and
+        // fold its mass into the running center-of-mass.
+        if self.children.is_none() {
+            // This was a leaf: subdivide and re-insert the existing body.
+            self.children = Some(Box::new([None, None, None, None]));
+            if let Some(existing) = self.body.take() {
+                self.insert_into_child(existing, bodies, depth);
+            }
+        }
+
+        self.insert_into_child(index, bodies, depth);
+        self.accumulate(x, y, m);
+    }
+
+    // Fold a body of mass `m` at (x, y) into this node's total mass and
+    // center-of-mass.
+    fn accumulate(&mut self, x: f64, y: f64, m: f64) {
+        let total = self.mass + m;
+        self.com_x = (self.com_x * self.mass + x * m) / total;
+        self.com_y = (self.com_y * self.mass + y * m) / total;
+        self.mass = total;
+    }
+
+    fn insert_into_child(&mut self, index: usize, bodies: &[(f64, f64, f64)], depth: usize) {
+        let (x, y, _) = bodies[index];
+        let quadrant = self.quad.quadrant(x, y);
+        let children = self.children.as_mut().unwrap();
+        let slot = &mut children[quadrant];
+        if slot.is_none() {
+            *slot = Some(Node::new(self.quad.child(quadrant)));
+        }
+        slot.as_mut().unwrap().insert(index, bodies, depth + 1);
+    }
+
+    // Accumulate the acceleration this node exerts on a body at (x, y),
+    // recursing into children only when the node is too close/large to treat
+    // as a single point (s/d >= theta).
+    fn accelerate(&self, index: usize, x: f64, y: f64, theta: f64, acc: &mut (f64, f64)) {
+        if self.mass == 0. {
+            return;
+        }
+
+        // Leaf: sum directly, skipping self-interaction.
+        if let Some(body) = self.body {
+            if self.children.is_none() {
+                if body != index {
+                    add_point_mass(x, y, self.com_x, self.com_y, self.mass, acc);
+                }
+                return;
+            }
+        }
+
+        let dx = self.com_x - x;
+        let dy = self.com_y - y;
+        let distance = (dx * dx + dy * dy).sqrt();
+        let width = self.quad.half * 2.;
+
+        if distance > 0. && width / distance < theta {
+            add_point_mass(x, y, self.com_x, self.com_y, self.mass, acc);
+        } else if let Some(children) = &self.children {
+            for child in children.iter().flatten() {
+                child.accelerate(index, x, y, theta, acc);
+            }
+        }
+    }
+}
+
+// Acceleration contribution of a point mass at (mx, my) on a body at (x, y).
+fn add_point_mass(x: f64, y: f64, mx: f64, my: f64, mass: f64, acc: &mut (f64, f64)) {
+    let dx = mx - x;
+    let dy = my - y;
+    let distance = (dx * dx + dy * dy).sqrt();
+    if distance == 0. {
+        return;
+    }
+    let factor = GRAVITY * mass / distance.powi(3);
+    acc.0 += factor * dx;
+    acc.1 += factor * dy;
+}
+
+// Build a quadtree over all bodies and return the approximate acceleration on
+// each, mirroring the exact solver's output layout.
+pub fn compute_accelerations(bodies: &[(f64, f64, f64)], theta: f64) -> Vec<(f64, f64)> {
+    if bodies.is_empty() {
+        return Vec::new();
+    }
+
+    // Square bounding box covering every body.
+    let mut min_x = f64::MAX;
+    let mut min_y = f64::MAX;
+    let mut max_x = f64::MIN;
+    let mut max_y = f64::MIN;
+    for &(x, y, _) in bodies {
+        min_x = min_x.min(x);
+        min_y = min_y.min(y);
+        max_x = max_x.max(x);
+        max_y = max_y.max(y);
+    }
+    let half = ((max_x - min_x).max(max_y - min_y) / 2.).max(1.);
+    let root_quad = Quad {
+        cx: (min_x + max_x) / 2.,
+        cy: (min_y + max_y) / 2.,
+        half,
+    };
+
+    let mut root = Node::new(root_quad);
+    for index in 0..bodies.len() {
+        root.insert(index, bodies, 0);
+    }
+
+    bodies
+        .iter()
+        .enumerate()
+        .map(|(index, &(x, y, _))| {
+            let mut acc = (0., 0.);
+            root.accelerate(index, x, y, theta, &mut acc);
+            acc
+        })
+        .collect()
+}
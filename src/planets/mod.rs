@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::{default, f32::consts::PI};
 
 use bevy::{
@@ -6,134 +7,155 @@ use bevy::{
     render::render_resource::PrimitiveTopology,
     sprite::{MaterialMesh2dBundle, Mesh2dHandle},
 };
+use serde::Deserialize;
+
+mod barnes_hut;
 
 /// Everything to do with updating planet positions
 pub struct PlanetsPlugin;
 
 impl Plugin for PlanetsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, spawn_planets)
-            .add_systems(Update, (apply_gravity, update_planets, draw_planet_trails));
+        app.init_resource::<GravityConfig>()
+            .init_resource::<OrbitDisplay>()
+            .init_resource::<SimTime>()
+            .insert_resource(load_system_config())
+            .add_systems(Startup, spawn_planets)
+            .add_systems(
+                Update,
+                (
+                    simulation_controls,
+                    apply_gravity,
+                    update_planets,
+                    draw_planet_trails,
+                    toggle_orbit_display,
+                    draw_orbit_rings,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// How the N-body step sums gravitational acceleration.
+#[derive(PartialEq, Eq)]
+enum GravitySummation {
+    /// Exact O(n^2) pairwise sum. Best for small body counts.
+    Exact,
+    /// Barnes–Hut quadtree approximation, roughly O(n log n).
+    BarnesHut,
+}
+
+/// Runtime controls for the gravity solver: which summation to use and the
+/// Barnes–Hut opening angle `theta` (smaller is more accurate, slower).
+#[derive(Resource)]
+struct GravityConfig {
+    summation: GravitySummation,
+    theta: f64,
+}
+
+impl Default for GravityConfig {
+    fn default() -> Self {
+        GravityConfig {
+            summation: GravitySummation::Exact,
+            theta: 0.5,
+        }
+    }
+}
+
+/// Whether the predicted orbital rings are drawn, toggled like the referenced
+/// game's "augmented reality" overlay. Defaults on.
+#[derive(Resource)]
+struct OrbitDisplay(bool);
+
+impl Default for OrbitDisplay {
+    fn default() -> Self {
+        OrbitDisplay(true)
+    }
+}
+
+// Number of points sampled around a predicted orbit ellipse.
+const ORBIT_SEGMENTS: usize = 128;
+
+/// Simulation time controls: the signed per-frame timestep, pause state, and a
+/// pending single-step request.
+#[derive(Resource)]
+pub(crate) struct SimTime {
+    timestep: f64,
+    paused: bool,
+    step_once: bool,
+}
+
+impl Default for SimTime {
+    fn default() -> Self {
+        SimTime {
+            timestep: TIMESTEP,
+            paused: false,
+            step_once: false,
+        }
+    }
+}
+
+impl SimTime {
+    /// Current speed relative to the 1x base of one day per frame. Negative
+    /// while running backward.
+    pub(crate) fn multiplier(&self) -> f64 {
+        self.timestep / TIMESTEP
+    }
+
+    /// Whether the simulation is currently paused.
+    pub(crate) fn is_paused(&self) -> bool {
+        self.paused
     }
 }
 
 // Atomic units in meters
-const AU: f64 = 149.6e6 * 1000.;
+pub(crate) const AU: f64 = 149.6e6 * 1000.;
 // Gravity (G)
 const GRAVITY: f64 = 6.67428e-11;
 // For scaling
 const SCALE: f64 = 250. / AU;
-// To represent duration of orbit
+// Base timestep: one day per frame at 1x speed.
 const TIMESTEP: f64 = 3600. * 24.;
+// Largest timestep the integrator takes in a single sub-step; anything larger
+// is split so big speed-ups don't destroy orbit fidelity.
+const DT_MAX: f64 = TIMESTEP;
 
-const SUN_DIAMETER: f32 = 75.;
-
-const SUN_RADIUS: f32 = 69634.;
-const MERCURY_RADIUS: f32 = 2440. / SUN_RADIUS;
-const VENUS_RADIUS: f32 = 6052. / SUN_RADIUS;
-const EARTH_RADIUS: f32 = 6371. / SUN_RADIUS;
-const MARS_RADIUS: f32 = 3390. / SUN_RADIUS;
-const JUPITER_RADIUS: f32 = 69911. / SUN_RADIUS;
-const SATURN_RADIUS: f32 = 58232. / SUN_RADIUS;
-const URANUS_RADIUS: f32 = 25362. / SUN_RADIUS;
-const NEPTUNE_RADIUS: f32 = 24622. / SUN_RADIUS;
-
-// Planetery sizes
-const SUN_SIZE: Vec3 = Vec3::new(SUN_DIAMETER, SUN_DIAMETER, 0.);
-const MERCURY_SIZE: Vec3 = Vec3::new(
-    MERCURY_RADIUS * SUN_DIAMETER,
-    MERCURY_RADIUS * SUN_DIAMETER,
-    0.,
-);
-const VENUS_SIZE: Vec3 = Vec3::new(VENUS_RADIUS * SUN_DIAMETER, VENUS_RADIUS * SUN_DIAMETER, 0.);
-const EARTH_SIZE: Vec3 = Vec3::new(EARTH_RADIUS * SUN_DIAMETER, EARTH_RADIUS * SUN_DIAMETER, 0.);
-const MARS_SIZE: Vec3 = Vec3::new(MARS_RADIUS * SUN_DIAMETER, MARS_RADIUS * SUN_DIAMETER, 0.);
-const JUPITER_SIZE: Vec3 = Vec3::new(
-    JUPITER_RADIUS * SUN_DIAMETER,
-    JUPITER_RADIUS * SUN_DIAMETER,
-    0.,
-);
-const SATURN_SIZE: Vec3 = Vec3::new(
-    SATURN_RADIUS * SUN_DIAMETER,
-    SATURN_RADIUS * SUN_DIAMETER,
-    0.,
-);
-const URANUS_SIZE: Vec3 = Vec3::new(
-    URANUS_RADIUS * SUN_DIAMETER,
-    URANUS_RADIUS * SUN_DIAMETER,
-    0.,
-);
-const NEPTUNE_SIZE: Vec3 = Vec3::new(
-    NEPTUNE_RADIUS * SUN_DIAMETER,
-    NEPTUNE_RADIUS * SUN_DIAMETER,
-    0.,
-);
-
-// Plantary colors
-const SUN_COLOR: Color = Color::YELLOW;
-const MERCURY_COLOR: Color = Color::RED;
-const VENUS_COLOR: Color = Color::BEIGE;
-const EARTH_COLOR: Color = Color::BLUE;
-const MARS_COLOR: Color = Color::ORANGE_RED;
-const JUPITER_COLOR: Color = Color::GREEN;
-const SATURN_COLOR: Color = Color::BEIGE;
-const URANUS_COLOR: Color = Color::rgb(0., 255., 255.);
-const NEPTUNE_COLOR: Color = Color::WHITE;
-
-// Relative positions
-const SUN_POSITION: Position = Position { x: 0., y: 0. };
-const MERCURY_POSITION: Position = Position {
-    x: 0.387 * AU,
-    y: 0.,
-};
-const VENUS_POSITION: Position = Position {
-    x: 0.72 * AU,
-    y: 0.,
-};
-const EARTH_POSITION: Position = Position { x: -1. * AU, y: 0. };
-const MARS_POSITION: Position = Position {
-    x: -1.524 * AU,
-    y: 0.,
-};
-const JUPITER_POSITION: Position = Position { x: 5.2 * AU, y: 0. };
-const SATURN_POSITION: Position = Position {
-    x: 9.54 * AU,
-    y: 0.,
-};
-const URANUS_POSITION: Position = Position {
-    x: 19.2 * AU,
-    y: 0.,
-};
-const NEPTUNE_POSITION: Position = Position {
-    x: 30.06 * AU,
-    y: 0.,
-};
+// Reference radius and on-screen diameter of the Sun; every body's sprite size
+// is scaled from its real radius against these.
+const SUN_RADIUS: f64 = 69634.;
+const SUN_DIAMETER: f64 = 75.;
 
-// Planetary masses
-const MASS_OF_SUN: f64 = 1.98892e30;
-const MASS_OF_VENUS: f64 = 4.87e24;
-const MASS_OF_MERCURY: f64 = 3.3e23;
-const MASS_OF_EARTH: f64 = 5.9742e24;
-const MASS_OF_MARS: f64 = 6.39e23;
-const MASS_OF_JUPITER: f64 = 1898e24;
-const MASS_OF_SATURN: f64 = 568e24;
-const MASS_OF_URANUS: f64 = 86.8e24;
-const MASS_OF_NEPTUNE: f64 = 102e24;
+// Path to the scenario file describing every body. Loaded at startup so bodies
+// can be added, removed, or swapped for an alternate preset without recompiling.
+const SYSTEM_CONFIG_PATH: &str = "assets/solar_system.ron";
 
 #[derive(Component)]
-struct Planet;
+pub(crate) struct Planet;
+
+/// Marks the planet the user has clicked on; the camera follows it and the
+/// diagnostics overlay reports its state. At most one body carries it.
+#[derive(Component)]
+pub(crate) struct Selected;
 
 #[derive(Component, Clone, Copy)]
-struct Position {
-    x: f64,
-    y: f64,
+pub(crate) struct Position {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
 }
 
 #[derive(Component)]
-struct Mass(f64);
+pub(crate) struct Mass(pub(crate) f64);
 
+#[derive(Component, Clone, Copy)]
+pub(crate) struct Velocity {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+}
+
+// Cached acceleration from the end of the previous step, reused for the
+// first half-kick of the leapfrog integrator.
 #[derive(Component)]
-struct Velocity {
+struct Acceleration {
     x: f64,
     y: f64,
 }
@@ -174,6 +196,7 @@ struct SpaceObjectBundle {
     planet: Planet,
     mass: Mass,
     velocity: Velocity,
+    acceleration: Acceleration,
     position: Position,
     material2d: MaterialMesh2dBundle<ColorMaterial>,
     trail: PlanetTrail,
@@ -194,6 +217,7 @@ impl SpaceObjectBundle {
             planet: Planet,
             mass: mass,
             velocity: velocity,
+            acceleration: Acceleration { x: 0., y: 0. },
             material2d: material2d,
             position: position,
             trail: PlanetTrail::default(),
@@ -201,198 +225,216 @@ impl SpaceObjectBundle {
     }
 }
 
-// Show the planets
+/// A single body as described in the scenario file. Distances are in AU and
+/// velocities in km/s; both are relative to `parent` when one is named.
+#[derive(Deserialize)]
+struct BodyDef {
+    name: String,
+    mass: f64,
+    radius: f64,
+    color: (f32, f32, f32),
+    position: (f64, f64),
+    velocity: (f64, f64),
+    #[serde(default)]
+    parent: Option<String>,
+}
+
+/// The set of bodies making up a scenario, loaded from the config asset.
+#[derive(Resource, Deserialize)]
+struct SystemConfig {
+    bodies: Vec<BodyDef>,
+}
+
+// Read and parse the scenario file. A malformed or missing config is a setup
+// error, so fail loudly rather than silently starting with no bodies.
+fn load_system_config() -> SystemConfig {
+    let source = std::fs::read_to_string(SYSTEM_CONFIG_PATH)
+        .unwrap_or_else(|err| panic!("failed to read {SYSTEM_CONFIG_PATH}: {err}"));
+    ron::from_str(&source)
+        .unwrap_or_else(|err| panic!("failed to parse {SYSTEM_CONFIG_PATH}: {err}"))
+}
+
+// Spawn every body from the scenario, resolving moons relative to their parent
+// into the absolute frame the gravity solver works in.
 fn spawn_planets(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    config: Res<SystemConfig>,
 ) {
-    // List of planets with their mass, velocity, position, and size
-    let mut planet_list: Vec<(f64, Velocity, Position, Vec3, Color)> = Vec::new();
-
-    // Sun
-    planet_list.push((
-        MASS_OF_SUN,
-        Velocity { x: 0., y: 0. },
-        SUN_POSITION,
-        SUN_SIZE,
-        SUN_COLOR,
-    ));
-
-    // Mercury
-    planet_list.push((
-        MASS_OF_MERCURY,
-        Velocity {
-            x: 0.,
-            y: 47.4 * 1000.,
-        },
-        MERCURY_POSITION,
-        MERCURY_SIZE,
-        MERCURY_COLOR,
-    ));
-
-    // Venus
-    planet_list.push((
-        MASS_OF_VENUS,
-        Velocity {
-            x: 0.,
-            y: 35. * 1000.,
-        },
-        VENUS_POSITION,
-        VENUS_SIZE,
-        VENUS_COLOR,
-    ));
-
-    // Earth
-    planet_list.push((
-        MASS_OF_EARTH,
-        Velocity {
-            x: 0.,
-            y: 29.783 * 1000.,
-        },
-        EARTH_POSITION,
-        EARTH_SIZE,
-        EARTH_COLOR,
-    ));
-
-    // Mars
-    planet_list.push((
-        MASS_OF_MARS,
-        Velocity {
-            x: 0.,
-            y: 24.077 * 1000.,
-        },
-        MARS_POSITION,
-        MARS_SIZE,
-        MARS_COLOR,
-    ));
-
-    // Jupiter
-    planet_list.push((
-        MASS_OF_JUPITER,
-        Velocity {
-            x: 0.,
-            y: 13.1 * 1000.,
-        },
-        JUPITER_POSITION,
-        JUPITER_SIZE,
-        JUPITER_COLOR,
-    ));
-
-    // Saturn
-    planet_list.push((
-        MASS_OF_SATURN,
-        Velocity {
-            x: 0.,
-            y: 9.7 * 1000.,
-        },
-        SATURN_POSITION,
-        SATURN_SIZE,
-        SATURN_COLOR,
-    ));
-
-    // Uranus
-    planet_list.push((
-        MASS_OF_URANUS,
-        Velocity {
-            x: 0.,
-            y: 6.8 * 1000.,
-        },
-        URANUS_POSITION,
-        URANUS_SIZE,
-        URANUS_COLOR,
-    ));
-
-    // Neptune
-    planet_list.push((
-        MASS_OF_NEPTUNE,
-        Velocity {
-            x: 0.,
-            y: 4.7 * 1000.,
-        },
-        NEPTUNE_POSITION,
-        NEPTUNE_SIZE,
-        NEPTUNE_COLOR,
-    ));
-
-    for (mass, velocity, position, size, color) in planet_list.iter() {
-        commands.spawn(SpaceObjectBundle::new(
-            Mass(*mass),
-            Velocity {
-                x: velocity.x,
-                y: velocity.y,
-            },
-            *position,
-            MaterialMesh2dBundle {
-                mesh: meshes.add(shape::Circle::default().into()).into(),
-                material: materials.add(ColorMaterial::from(*color)),
-                transform: Transform::from_translation(Vec3::new(
-                    position.x as f32,
-                    position.y as f32,
-                    0.,
-                ))
-                .with_scale(*size),
-                ..default()
-            },
+    // Absolute (position, velocity) of each body, keyed by name, so children
+    // can be offset from an already-resolved parent.
+    let mut resolved: HashMap<&str, (Position, Velocity)> = HashMap::new();
+
+    for body in &config.bodies {
+        // Parent state, or the origin for top-level bodies.
+        let (parent_position, parent_velocity) = match &body.parent {
+            Some(parent) => *resolved.get(parent.as_str()).unwrap_or_else(|| {
+                panic!("body {:?} references unknown parent {:?}", body.name, parent)
+            }),
+            None => (Position { x: 0., y: 0. }, Velocity { x: 0., y: 0. }),
+        };
+
+        let position = Position {
+            x: parent_position.x + body.position.0 * AU,
+            y: parent_position.y + body.position.1 * AU,
+        };
+        let velocity = Velocity {
+            x: parent_velocity.x + body.velocity.0 * 1000.,
+            y: parent_velocity.y + body.velocity.1 * 1000.,
+        };
+
+        resolved.insert(&body.name, (position, velocity));
+
+        // Scale the sprite from the body's real radius against the Sun's.
+        let diameter = (body.radius / SUN_RADIUS * SUN_DIAMETER) as f32;
+        let size = Vec3::new(diameter, diameter, 0.);
+        let color = Color::rgb(body.color.0, body.color.1, body.color.2);
+
+        commands.spawn((
+            Name::new(body.name.clone()),
+            SpaceObjectBundle::new(
+                Mass(body.mass),
+                velocity,
+                position,
+                MaterialMesh2dBundle {
+                    mesh: meshes.add(shape::Circle::default().into()).into(),
+                    material: materials.add(ColorMaterial::from(color)),
+                    transform: Transform::from_translation(Vec3::new(
+                        position.x as f32,
+                        position.y as f32,
+                        0.,
+                    ))
+                    .with_scale(size),
+                    ..default()
+                },
+            ),
         ));
     }
 }
 
-// Runs f = G * M1 * M2 / d * d
+// Advances the system one step with a symplectic kick-drift-kick (velocity
+// Verlet / leapfrog) integrator, which conserves energy far better than a
+// single forward Euler update and keeps orbits closed over long runs.
 fn apply_gravity(
-    mut planet_query: Query<(Entity, &mut Position, &mut Velocity, &Mass), With<Planet>>,
+    mut planet_query: Query<(&mut Position, &mut Velocity, &mut Acceleration, &Mass), With<Planet>>,
+    config: Res<GravityConfig>,
+    mut sim: ResMut<SimTime>,
 ) {
-    let mut velocity_store: Vec<(f64, f64)> = Vec::new();
-
-    for (entity, position, velocity, mass) in planet_query.iter() {
-        let mut total_fx = 0.;
-        let mut total_fy = 0.;
-        for (other_entity, other_position, _other_veloctiy, other_mass) in planet_query.iter() {
-            if entity != other_entity {
-                let total_force = calculate_force(position, mass, other_position, other_mass);
-                total_fx += total_force.0;
-                total_fy += total_force.1;
-            }
-        }
-        let mut new_x = velocity.x;
-        let mut new_y = velocity.y;
-        new_x += total_fx / mass.0 * TIMESTEP;
-        new_y += total_fy / mass.0 * TIMESTEP;
-        velocity_store.push((new_x, new_y));
+    // Hold still while paused, unless a single frame was requested.
+    if sim.paused && !sim.step_once {
+        return;
+    }
+    sim.step_once = false;
+
+    // Split large timesteps into equal sub-steps so speed-ups don't wreck the
+    // integrator's accuracy.
+    let sub_steps = (sim.timestep.abs() / DT_MAX).ceil().max(1.) as usize;
+    let dt = sim.timestep / sub_steps as f64;
+    for _ in 0..sub_steps {
+        leapfrog_step(&mut planet_query, dt, &config);
     }
+}
 
-    let mut i = 0;
-    for (_entity, mut position, mut velocity, _mass) in planet_query.iter_mut() {
-        if let Some(result) = velocity_store.get(i) {
-            let (x, y) = result;
-            velocity.x = *x;
-            velocity.y = *y;
-            position.x += velocity.x * TIMESTEP;
-            position.y += velocity.y * TIMESTEP;
-            i += 1;
-        }
+// A single kick-drift-kick step over the given timestep.
+fn leapfrog_step(
+    planet_query: &mut Query<
+        (&mut Position, &mut Velocity, &mut Acceleration, &Mass),
+        With<Planet>,
+    >,
+    dt: f64,
+    config: &GravityConfig,
+) {
+    // First half-kick with the accelerations cached from the end of the
+    // previous step, then drift the positions forward a full step.
+    let mut bodies: Vec<(f64, f64, f64)> = Vec::new();
+    for (mut position, mut velocity, acceleration, mass) in planet_query.iter_mut() {
+        velocity.x += acceleration.x * dt / 2.;
+        velocity.y += acceleration.y * dt / 2.;
+        position.x += velocity.x * dt;
+        position.y += velocity.y * dt;
+        bodies.push((position.x, position.y, mass.0));
+    }
+
+    // Recompute accelerations from the drifted positions, either exactly or
+    // through the Barnes–Hut tree depending on the active configuration.
+    let new_accelerations = match config.summation {
+        GravitySummation::Exact => compute_accelerations(&bodies),
+        GravitySummation::BarnesHut => barnes_hut::compute_accelerations(&bodies, config.theta),
+    };
+
+    // Second half-kick, caching the fresh accelerations for the next step.
+    for (i, (_position, mut velocity, mut acceleration, _mass)) in
+        planet_query.iter_mut().enumerate()
+    {
+        let (ax, ay) = new_accelerations[i];
+        velocity.x += ax * dt / 2.;
+        velocity.y += ay * dt / 2.;
+        acceleration.x = ax;
+        acceleration.y = ay;
     }
 }
 
-fn calculate_force(
-    position: &Position,
-    mass: &Mass,
-    other_position: &Position,
-    other_mass: &Mass,
-) -> (f64, f64) {
-    let distance_x = other_position.x - position.x;
-    let distance_y = other_position.y - position.y;
+// Keyboard controls for pausing, single-stepping, scaling, and reversing time,
+// plus switching the gravity solver between the exact and Barnes–Hut sums.
+fn simulation_controls(
+    keys: Res<Input<KeyCode>>,
+    mut sim: ResMut<SimTime>,
+    mut gravity: ResMut<GravityConfig>,
+) {
+    if keys.just_pressed(KeyCode::Space) {
+        sim.paused = !sim.paused;
+    }
 
-    let total_distance = (distance_x.powi(2) + distance_y.powi(2)).sqrt();
+    // Toggle between exact O(n^2) and Barnes–Hut summation.
+    if keys.just_pressed(KeyCode::G) {
+        gravity.summation = if gravity.summation == GravitySummation::Exact {
+            GravitySummation::BarnesHut
+        } else {
+            GravitySummation::Exact
+        };
+    }
 
-    let force =
-        GRAVITY as f64 * mass.0 as f64 * other_mass.0 as f64 / total_distance.powi(2) as f64;
-    let theta = distance_y.atan2(distance_x) as f64;
+    // Advance exactly one frame while paused.
+    if keys.just_pressed(KeyCode::Period) {
+        sim.step_once = true;
+    }
 
-    let force_x = theta.cos() * force;
-    let force_y = theta.sin() * force;
+    // Double or halve the speed.
+    if keys.just_pressed(KeyCode::BracketRight) {
+        sim.timestep *= 2.;
+    }
+    if keys.just_pressed(KeyCode::BracketLeft) {
+        sim.timestep /= 2.;
+    }
+
+    // Flip the direction of time.
+    if keys.just_pressed(KeyCode::R) {
+        sim.timestep = -sim.timestep;
+    }
+}
 
-    (force_x, force_y)
+// Exact O(n^2) gravitational acceleration on each body:
+// a_i = sum_j G * m_j * (r_j - r_i) / |r_j - r_i|^3, skipping self-interaction.
+fn compute_accelerations(bodies: &[(f64, f64, f64)]) -> Vec<(f64, f64)> {
+    let mut accelerations = Vec::with_capacity(bodies.len());
+    for (i, &(xi, yi, _mi)) in bodies.iter().enumerate() {
+        let mut ax = 0.;
+        let mut ay = 0.;
+        for (j, &(xj, yj, mj)) in bodies.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            let dx = xj - xi;
+            let dy = yj - yi;
+            let distance = (dx * dx + dy * dy).sqrt();
+            let factor = GRAVITY * mj / distance.powi(3);
+            ax += factor * dx;
+            ay += factor * dy;
+        }
+        accelerations.push((ax, ay));
+    }
+    accelerations
 }
 
 // Update planet positions after force has been applied
@@ -419,3 +461,78 @@ fn draw_planet_trails(
         gizmos.linestrip_2d(trail.positions.clone(), Color::GREEN);
     }
 }
+
+// Toggle the predicted orbit overlay on/off.
+fn toggle_orbit_display(keys: Res<Input<KeyCode>>, mut display: ResMut<OrbitDisplay>) {
+    if keys.just_pressed(KeyCode::O) {
+        display.0 = !display.0;
+    }
+}
+
+// Draw each planet's full predicted orbit as a closed ellipse, computed
+// analytically from its Keplerian elements relative to the Sun. Recomputed
+// every frame so perturbations show up immediately.
+fn draw_orbit_rings(
+    display: Res<OrbitDisplay>,
+    planet_query: Query<(&Position, &Velocity, &Mass), With<Planet>>,
+    mut gizmos: Gizmos,
+) {
+    if !display.0 {
+        return;
+    }
+
+    // Treat the most massive body as the central Sun.
+    let Some((sun_position, sun_velocity, sun_mass)) = planet_query
+        .iter()
+        .max_by(|a, b| a.2 .0.partial_cmp(&b.2 .0).unwrap())
+    else {
+        return;
+    };
+
+    for (position, velocity, mass) in planet_query.iter() {
+        // State relative to the Sun.
+        let rx = position.x - sun_position.x;
+        let ry = position.y - sun_position.y;
+        let vx = velocity.x - sun_velocity.x;
+        let vy = velocity.y - sun_velocity.y;
+
+        let r = (rx * rx + ry * ry).sqrt();
+        if r == 0. {
+            // This is the Sun itself.
+            continue;
+        }
+
+        let mu = GRAVITY * (sun_mass.0 + mass.0);
+        let v2 = vx * vx + vy * vy;
+
+        // Semi-major axis from the vis-viva specific orbital energy.
+        let eps = v2 / 2. - mu / r;
+        let a = -mu / (2. * eps);
+
+        // Eccentricity vector gives eccentricity and periapsis orientation.
+        let rv = rx * vx + ry * vy;
+        let e_vec_x = ((v2 - mu / r) * rx - rv * vx) / mu;
+        let e_vec_y = ((v2 - mu / r) * ry - rv * vy) / mu;
+        let e = (e_vec_x * e_vec_x + e_vec_y * e_vec_y).sqrt();
+
+        // Skip unbound (parabolic/hyperbolic) trajectories to avoid NaNs.
+        if e >= 1. {
+            continue;
+        }
+
+        let omega = e_vec_y.atan2(e_vec_x);
+        let semi_latus = a * (1. - e * e);
+
+        let mut points: Vec<Vec2> = Vec::with_capacity(ORBIT_SEGMENTS + 1);
+        for i in 0..=ORBIT_SEGMENTS {
+            let theta = i as f64 / ORBIT_SEGMENTS as f64 * 2. * PI as f64;
+            let radius = semi_latus / (1. + e * theta.cos());
+            let angle = theta + omega;
+            let x = (sun_position.x + radius * angle.cos()) * SCALE;
+            let y = (sun_position.y + radius * angle.sin()) * SCALE;
+            points.push(Vec2::new(x as f32, y as f32));
+        }
+
+        gizmos.linestrip_2d(points, Color::GRAY);
+    }
+}
@@ -0,0 +1,135 @@
+use bevy::prelude::*;
+
+use crate::camera::MainCamera;
+
+/// A procedural field of static stars drawn behind everything else, giving
+/// empty space some depth instead of flat black.
+pub struct StarfieldPlugin;
+
+// Seed for the deterministic star layout, so the sky looks identical each run.
+const STAR_SEED: u64 = 0x5_1ED_5EED;
+// How many stars to scatter.
+const STAR_COUNT: usize = 800;
+// Half-extent of the region stars are spread over, in world units.
+const STAR_SPREAD: f32 = 8000.;
+// z just below the planets (which sit at z = 0), still inside the default 2D
+// camera's depth range, which ends 0.1 below z = 0.
+const STAR_Z: f32 = -0.05;
+// Fraction of the camera translation the stars track, for a subtle parallax.
+const STAR_PARALLAX: f32 = 0.1;
+
+impl Plugin for StarfieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StarfieldVisible>()
+            .add_systems(Startup, spawn_starfield)
+            .add_systems(Update, (toggle_starfield, parallax_starfield));
+    }
+}
+
+/// Whether the starfield is shown. Toggleable so it can be hidden while
+/// benchmarking against the FPS counter.
+#[derive(Resource)]
+struct StarfieldVisible(bool);
+
+impl Default for StarfieldVisible {
+    fn default() -> Self {
+        StarfieldVisible(true)
+    }
+}
+
+/// Marks a star sprite and remembers its base position and base size, so
+/// parallax can be applied relative to the camera without drifting away, and
+/// the on-screen size can be kept constant across zoom levels.
+#[derive(Component)]
+struct Star {
+    base: Vec2,
+    size: f32,
+}
+
+// A tiny xorshift generator, used so the layout is deterministic from a seed
+// without pulling in an RNG dependency.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    // A pseudo-random f32 in [0, 1).
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+// Scatter the stars deterministically, with mild size and brightness variation.
+fn spawn_starfield(mut commands: Commands) {
+    let mut rng = Rng(STAR_SEED);
+
+    for _ in 0..STAR_COUNT {
+        let x = (rng.next_f32() * 2. - 1.) * STAR_SPREAD;
+        let y = (rng.next_f32() * 2. - 1.) * STAR_SPREAD;
+        let size = 0.5 + rng.next_f32() * 1.5;
+        let brightness = 0.4 + rng.next_f32() * 0.6;
+
+        commands.spawn((
+            Star {
+                base: Vec2::new(x, y),
+                size,
+            },
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(brightness, brightness, brightness),
+                    custom_size: Some(Vec2::splat(size)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x, y, STAR_Z),
+                ..default()
+            },
+        ));
+    }
+}
+
+// Show or hide every star when the starfield is toggled.
+fn toggle_starfield(
+    keys: Res<Input<KeyCode>>,
+    mut visible: ResMut<StarfieldVisible>,
+    mut star_query: Query<&mut Visibility, With<Star>>,
+) {
+    if !keys.just_pressed(KeyCode::B) {
+        return;
+    }
+    visible.0 = !visible.0;
+    let visibility = if visible.0 {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut star_visibility in &mut star_query {
+        *star_visibility = visibility;
+    }
+}
+
+// Track the camera by a fraction of its translation so panning gives a subtle
+// sense of depth, and scale each star by the projection scale so it stays a
+// visible size at any zoom level. The stars only cover `STAR_SPREAD` around
+// that offset, so panning or zooming out far enough leaves the field behind.
+fn parallax_starfield(
+    camera_query: Query<(&Transform, &OrthographicProjection), (With<MainCamera>, Without<Star>)>,
+    mut star_query: Query<(&Star, &mut Transform, &mut Sprite), Without<MainCamera>>,
+) {
+    let Ok((camera_transform, projection)) = camera_query.get_single() else {
+        return;
+    };
+    let offset = camera_transform.translation.truncate() * STAR_PARALLAX;
+    for (star, mut transform, mut sprite) in &mut star_query {
+        let position = star.base + offset;
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+        sprite.custom_size = Some(Vec2::splat(star.size * projection.scale));
+    }
+}